@@ -1,4 +1,5 @@
 use super::{Lint, LintKind, Linter, Suggestion};
+use crate::noun_chunks::NounChunkExt;
 use crate::{Document, Span, TokenStringExt};
 
 /// Detect sequences of words of the form "adjective of a".
@@ -53,9 +54,21 @@ fn is_bad_adjective(word: &str) -> bool {
         .any(|&adj| word.eq_ignore_ascii_case(adj))
 }
 
+/// Whether `adjective`'s span is covered by a noun chunk that extends to its
+/// left (a determiner, possessive, or other modifier was prepended) --
+/// i.e. it's being used as a noun here, the way `ADJECTIVE_BLACKLIST` is
+/// meant to catch for "much"/"part", but derived from the chunk itself
+/// instead of a fixed word list.
+fn heads_noun_chunk(noun_chunks: &[Span], adjective: &Span) -> bool {
+    noun_chunks
+        .iter()
+        .any(|chunk| chunk.start < adjective.start && chunk.end >= adjective.end)
+}
+
 impl Linter for AdjectiveOfA {
     fn lint(&mut self, document: &Document) -> Vec<Lint> {
         let mut lints = Vec::new();
+        let noun_chunks: Vec<Span> = document.iter_noun_chunks().collect();
 
         for i in document.iter_adjective_indices() {
             let adjective = document.get_token(i).unwrap();
@@ -73,7 +86,7 @@ impl Linter for AdjectiveOfA {
                 continue;
             }
             // Some adjectives still create false positives even with the extra context
-            if is_bad_adjective(&adj_str) {
+            if is_bad_adjective(&adj_str) || heads_noun_chunk(&noun_chunks, &adjective.span) {
                 continue;
             }
 
@@ -220,6 +233,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn dont_flag_good_headed_by_a_determiner() {
+        // "good" is whitelisted, but here it's the head of its own noun
+        // chunk ("the good"), not the adjective in an "adjective of a"
+        // construction -- this only passes because of `heads_noun_chunk`.
+        assert_lint_count("for the good of a nation", AdjectiveOfA, 0);
+    }
+
     #[test]
     fn dont_flag_much() {
         // "much of" is correct idiomatic usage
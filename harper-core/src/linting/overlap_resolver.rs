@@ -0,0 +1,95 @@
+use super::Lint;
+use crate::Span;
+
+/// The result of [`resolve_overlaps`]: the lints that survived, plus the
+/// ones a higher-priority or earlier-sorted lint suppressed.
+pub struct OverlapResolution {
+    pub kept: Vec<Lint>,
+    pub suppressed: Vec<Lint>,
+}
+
+/// Resolves overlapping lints from independent linters down to one coherent
+/// set, so callers see one suggestion per span instead of a raw union of
+/// matches.
+///
+/// Candidates are ordered by `priority` descending, then earlier span start,
+/// then longer span, and accepted greedily in that order: a lint is kept
+/// unless it overlaps a span already kept.
+pub fn resolve_overlaps(lints: Vec<Lint>) -> OverlapResolution {
+    let mut candidates = lints;
+    candidates.sort_by(|a, b| {
+        b.priority
+            .cmp(&a.priority)
+            .then_with(|| a.span.start.cmp(&b.span.start))
+            .then_with(|| span_len(&b.span).cmp(&span_len(&a.span)))
+    });
+
+    let mut kept: Vec<Lint> = Vec::new();
+    let mut suppressed: Vec<Lint> = Vec::new();
+
+    for lint in candidates {
+        if kept.iter().any(|kept_lint| spans_overlap(&kept_lint.span, &lint.span)) {
+            suppressed.push(lint);
+        } else {
+            kept.push(lint);
+        }
+    }
+
+    kept.sort_by_key(|lint| lint.span.start);
+
+    OverlapResolution { kept, suppressed }
+}
+
+fn span_len(span: &Span) -> usize {
+    span.end - span.start
+}
+
+fn spans_overlap(a: &Span, b: &Span) -> bool {
+    a.start < b.end && b.start < a.end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::resolve_overlaps;
+    use crate::Span;
+    use crate::linting::{Lint, LintKind};
+
+    fn lint(start: usize, end: usize, priority: u8) -> Lint {
+        Lint {
+            span: Span::new(start, end),
+            lint_kind: LintKind::Style,
+            suggestions: Vec::new(),
+            message: String::new(),
+            priority,
+        }
+    }
+
+    #[test]
+    fn keeps_non_overlapping_lints() {
+        let result = resolve_overlaps(vec![lint(0, 3, 50), lint(5, 8, 50)]);
+        assert_eq!(result.kept.len(), 2);
+        assert!(result.suppressed.is_empty());
+    }
+
+    #[test]
+    fn outer_span_is_suppressed_by_higher_priority_nested_span() {
+        let result = resolve_overlaps(vec![lint(0, 10, 40), lint(2, 4, 90)]);
+        assert_eq!(result.kept.len(), 1);
+        assert_eq!(result.kept[0].priority, 90);
+        assert_eq!(result.suppressed.len(), 1);
+    }
+
+    #[test]
+    fn equal_priority_partial_overlap_keeps_earlier_start() {
+        let result = resolve_overlaps(vec![lint(5, 10, 60), lint(0, 7, 60)]);
+        assert_eq!(result.kept.len(), 1);
+        assert_eq!(result.kept[0].span.start, 0);
+    }
+
+    #[test]
+    fn equal_priority_equal_start_keeps_longer_span() {
+        let result = resolve_overlaps(vec![lint(0, 5, 60), lint(0, 9, 60)]);
+        assert_eq!(result.kept.len(), 1);
+        assert_eq!(result.kept[0].span.end, 9);
+    }
+}
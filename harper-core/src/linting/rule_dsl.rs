@@ -0,0 +1,445 @@
+use std::error::Error;
+use std::fmt;
+
+use super::{Lint, LintKind, Linter, Suggestion};
+use crate::patterns::{
+    BacktrackingPattern, BacktrackingSequence, Invert, Pattern, RepeatingPattern,
+    WhitespacePattern, WordSet,
+};
+use crate::{Document, Span, Token, TokenStringExt};
+
+/// How a rule's word patterns should be compared against document tokens.
+///
+/// Named after the flags used by Grammalecte's rule compiler, which this
+/// format borrows from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaseMode {
+    /// `i`: compare ignoring case entirely.
+    Insensitive,
+    /// `s`: compare exactly as written in the rule.
+    Sensitive,
+    /// `u`: compare exactly as written, but also accept an all-uppercase
+    /// spelling (e.g. acronyms or shouted text).
+    UppercaseAllowed,
+}
+
+impl CaseMode {
+    fn parse(flag: &str) -> Option<Self> {
+        match flag {
+            "i" => Some(Self::Insensitive),
+            "s" => Some(Self::Sensitive),
+            "u" => Some(Self::UppercaseAllowed),
+            _ => None,
+        }
+    }
+
+    fn word_matches(&self, candidate: &str, rule_word: &str) -> bool {
+        match self {
+            Self::Insensitive => candidate.eq_ignore_ascii_case(rule_word),
+            Self::Sensitive => candidate == rule_word,
+            Self::UppercaseAllowed => candidate == rule_word || candidate == rule_word.to_uppercase(),
+        }
+    }
+}
+
+/// An error produced while compiling a single rule line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuleCompileError {
+    /// The line had no pattern section to compile.
+    EmptyPattern,
+    /// The `/<flag>` case-mode marker was present but unrecognized.
+    UnknownCaseMode(String),
+    /// `priority=<n>` was present but `<n>` did not parse as an integer.
+    InvalidPriority(String),
+    /// The rule had no `=>` replacement section.
+    MissingReplacement,
+}
+
+impl fmt::Display for RuleCompileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyPattern => write!(f, "rule has an empty pattern"),
+            Self::UnknownCaseMode(flag) => write!(f, "unknown case mode `{flag}`"),
+            Self::InvalidPriority(value) => write!(f, "invalid priority `{value}`"),
+            Self::MissingReplacement => write!(f, "rule is missing a `=>` replacement"),
+        }
+    }
+}
+
+impl Error for RuleCompileError {}
+
+/// A single rule compiled from DSL text into the existing [`Pattern`] tree.
+///
+/// Produced by [`RuleCompiler::compile`] and consumed by [`DslRuleSet`].
+pub struct CompiledRule {
+    pattern: Box<dyn Pattern>,
+    /// Whether the match must start at a word boundary (not immediately
+    /// preceded by another word token).
+    left_boundary: bool,
+    /// Whether the match must end at a word boundary (not immediately
+    /// followed by another word token).
+    right_boundary: bool,
+    replacement: String,
+    message: String,
+    priority: u8,
+}
+
+/// Compiles textual rules (one per line) into [`CompiledRule`]s.
+///
+/// A rule line has the shape:
+///
+/// ```text
+/// [^] <word>|<word|word|...>|<word>+|!<word> ... [$] /<case> priority=<n> => <replacement> # <message>
+/// ```
+///
+/// - `^` / `$` as the first/last pattern tokens request a boundary check so the
+///   rule only fires at the edges of a token sequence, rather than in the
+///   middle of a longer run of matching words.
+/// - `a|an` desugars to a word set that honours the rule's case mode (a plain
+///   [`WordSet`] under `/i`).
+/// - `word+` desugars to a run of one-or-more whitespace-separated words,
+///   matched via [`BacktrackingSequence`] so a later atom in the rule can
+///   still match against tokens the repetition would otherwise swallow.
+/// - `!word` desugars to an [`Invert`] of such a word set.
+///
+/// Atoms are joined with an implicit [`WhitespacePattern`] between them, and
+/// the whole rule is matched as one [`BacktrackingSequence`] so a greedy
+/// atom gives tokens back to a later one instead of failing the rule
+/// outright.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RuleCompiler;
+
+impl RuleCompiler {
+    /// Compile a single line of rule DSL into a [`CompiledRule`].
+    pub fn compile(&self, line: &str) -> Result<CompiledRule, RuleCompileError> {
+        let line = line.trim();
+
+        let (head, message) = match line.split_once('#') {
+            Some((head, message)) => (head.trim(), message.trim().to_string()),
+            None => (line, String::new()),
+        };
+
+        let (pattern_and_flags, replacement) = head
+            .split_once("=>")
+            .ok_or(RuleCompileError::MissingReplacement)?;
+        let replacement = replacement.trim().to_string();
+
+        let mut case_mode = CaseMode::Insensitive;
+        let mut priority: u8 = 32;
+        let mut pattern_tokens: Vec<&str> = Vec::new();
+
+        for word in pattern_and_flags.split_whitespace() {
+            if let Some(flag) = word.strip_prefix('/') {
+                case_mode = CaseMode::parse(flag)
+                    .ok_or_else(|| RuleCompileError::UnknownCaseMode(flag.to_string()))?;
+            } else if let Some(value) = word.strip_prefix("priority=") {
+                priority = value
+                    .parse()
+                    .map_err(|_| RuleCompileError::InvalidPriority(value.to_string()))?;
+            } else {
+                pattern_tokens.push(word);
+            }
+        }
+
+        let mut left_boundary = false;
+        let mut right_boundary = false;
+
+        if pattern_tokens.first() == Some(&"^") {
+            left_boundary = true;
+            pattern_tokens.remove(0);
+        }
+        if pattern_tokens.last() == Some(&"$") {
+            right_boundary = true;
+            pattern_tokens.pop();
+        }
+
+        if pattern_tokens.is_empty() {
+            return Err(RuleCompileError::EmptyPattern);
+        }
+
+        let mut stages: Vec<Box<dyn BacktrackingPattern>> = Vec::new();
+        for (i, tok) in pattern_tokens.into_iter().enumerate() {
+            if i > 0 {
+                stages.push(Box::new(WhitespacePattern));
+            }
+            stages.push(Self::compile_atom(tok, case_mode));
+        }
+
+        let pattern: Box<dyn Pattern> = Box::new(BacktrackingSequence::new(stages));
+
+        Ok(CompiledRule {
+            pattern,
+            left_boundary,
+            right_boundary,
+            replacement,
+            message,
+            priority,
+        })
+    }
+
+    /// Desugar a single space-separated pattern token into a [`BacktrackingPattern`].
+    ///
+    /// Handles negation (`!word`), repetition (`word+`), and alternation
+    /// (`a|an`), in that precedence order.
+    fn compile_atom(token: &str, case_mode: CaseMode) -> Box<dyn BacktrackingPattern> {
+        if let Some(rest) = token.strip_prefix('!') {
+            return Box::new(Invert::new(CaseWordSet::new(
+                rest.split('|').map(str::to_string).collect(),
+                case_mode,
+            )));
+        }
+
+        if let Some(rest) = token.strip_suffix('+') {
+            let words: Vec<String> = rest.split('|').map(str::to_string).collect();
+            return Box::new(BacktrackingSequence::new(vec![
+                Box::new(CaseWordSet::new(words.clone(), case_mode)),
+                Box::new(RepeatingPattern::new(
+                    Box::new(BacktrackingSequence::new(vec![
+                        Box::new(WhitespacePattern),
+                        Box::new(CaseWordSet::new(words, case_mode)),
+                    ])),
+                    0,
+                )),
+            ]));
+        }
+
+        let words: Vec<String> = token.split('|').map(str::to_string).collect();
+        Box::new(CaseWordSet::new(words, case_mode))
+    }
+
+    /// Compile every non-blank, non-comment-only line of a rule file.
+    pub fn compile_all(&self, source: &str) -> Result<Vec<CompiledRule>, RuleCompileError> {
+        source
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| self.compile(line))
+            .collect()
+    }
+}
+
+/// A [`WordSet`]-like pattern that respects a rule's [`CaseMode`] rather than
+/// always matching case-insensitively.
+struct CaseWordSet {
+    words: Vec<String>,
+    case_mode: CaseMode,
+}
+
+impl CaseWordSet {
+    fn new(words: Vec<String>, case_mode: CaseMode) -> Self {
+        Self { words, case_mode }
+    }
+}
+
+impl Pattern for CaseWordSet {
+    fn matches(&self, tokens: &[Token], source: &[char]) -> Option<usize> {
+        // Case-insensitive matching can reuse the existing `WordSet` combinator;
+        // the other two modes need the exact source text to apply their own rules.
+        if self.case_mode == CaseMode::Insensitive {
+            return WordSet::new(&self.words.iter().map(String::as_str).collect::<Vec<_>>())
+                .matches(tokens, source);
+        }
+
+        let token = tokens.first()?;
+        if !token.kind.is_word() {
+            return None;
+        }
+
+        let candidate: String = source[token.span.start..token.span.end].iter().collect();
+        self.words
+            .iter()
+            .any(|word| self.case_mode.word_matches(&candidate, word))
+            .then_some(1)
+    }
+}
+
+impl BacktrackingPattern for CaseWordSet {
+    fn match_lengths(&self, tokens: &[Token], source: &[char]) -> Vec<usize> {
+        self.matches(tokens, source).into_iter().collect()
+    }
+}
+
+/// A collection of [`CompiledRule`]s registered as a single [`Linter`].
+///
+/// This is the `Linter` contributors get "for free" once their rules compile:
+/// no Rust index arithmetic required, just rule text.
+pub struct DslRuleSet {
+    rules: Vec<CompiledRule>,
+}
+
+impl DslRuleSet {
+    /// Build a rule set directly from already-compiled rules.
+    pub fn new(rules: Vec<CompiledRule>) -> Self {
+        Self { rules }
+    }
+
+    /// Parse and compile `source` (one rule per line) into a rule set.
+    pub fn from_source(source: &str) -> Result<Self, RuleCompileError> {
+        Ok(Self::new(RuleCompiler.compile_all(source)?))
+    }
+}
+
+impl Linter for DslRuleSet {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        let mut lints = Vec::new();
+        let tokens = document.get_tokens();
+        let source = document.get_source();
+
+        for rule in &self.rules {
+            for i in document.iter_word_indices() {
+                if rule.left_boundary && word_precedes(tokens, i) {
+                    continue;
+                }
+
+                let Some(match_len) = rule.pattern.matches(&tokens[i..], source) else {
+                    continue;
+                };
+                let end = i + match_len;
+
+                // A pattern should never report a length past the slice it
+                // was given, but a misbehaving sub-pattern (e.g. `Invert`
+                // matching an empty remainder) shouldn't be able to turn
+                // into an out-of-bounds index here.
+                if end == i || end > tokens.len() {
+                    continue;
+                }
+
+                if rule.right_boundary && word_follows(tokens, end) {
+                    continue;
+                }
+
+                let span = Span::new(tokens[i].span.start, tokens[end - 1].span.end);
+                lints.push(Lint {
+                    span,
+                    lint_kind: LintKind::Style,
+                    suggestions: vec![Suggestion::ReplaceWith(
+                        rule.replacement.chars().collect(),
+                    )],
+                    message: rule.message.clone(),
+                    priority: rule.priority,
+                });
+            }
+        }
+
+        lints
+    }
+
+    fn description(&self) -> &str {
+        "Applies declaratively-defined style rules compiled from rule DSL text."
+    }
+}
+
+/// Whether the token immediately before `i`, skipping over whitespace, is
+/// itself a word (i.e. `i` is not at the edge of a token run).
+fn word_precedes(tokens: &[Token], i: usize) -> bool {
+    let mut j = i;
+    while j > 0 {
+        j -= 1;
+        if tokens[j].kind.is_whitespace() {
+            continue;
+        }
+        return tokens[j].kind.is_word();
+    }
+    false
+}
+
+/// Whether the token at or after `end`, skipping over whitespace, is itself
+/// a word (i.e. `end` is not at the edge of a token run).
+fn word_follows(tokens: &[Token], end: usize) -> bool {
+    let mut j = end;
+    while j < tokens.len() {
+        if tokens[j].kind.is_whitespace() {
+            j += 1;
+            continue;
+        }
+        return tokens[j].kind.is_word();
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CaseMode, DslRuleSet, RuleCompileError, RuleCompiler};
+    use crate::Document;
+    use crate::linting::Linter;
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+
+    fn compile(source: &str) -> DslRuleSet {
+        DslRuleSet::from_source(source).unwrap()
+    }
+
+    #[test]
+    fn compiles_alternation_with_case_insensitive_flag() {
+        let rules = compile("bad|awful of a|an /i priority=63 => fine idea # redundant phrasing");
+
+        assert_suggestion_result("This is a Bad of a day", rules, "This is a fine idea day");
+    }
+
+    #[test]
+    fn word_plus_backtracks_for_trailing_literal() {
+        // A greedy `bad+` would swallow both repetitions, leaving nothing
+        // for the trailing literal `bad` to match against -- without
+        // backtracking this rule could never fire.
+        let rules = compile("bad+ bad /i priority=10 => (redacted) # repetition");
+
+        assert_suggestion_result("bad bad", rules, "(redacted)");
+    }
+
+    #[test]
+    fn negation_excludes_the_word_immediately_after() {
+        assert_lint_count(
+            "That's a bad idea",
+            compile("bad !idea /i priority=10 => nope # negation"),
+            0,
+        );
+        assert_suggestion_result(
+            "That's a bad day",
+            compile("bad !idea /i priority=10 => nope # negation"),
+            "That's a nope day",
+        );
+    }
+
+    #[test]
+    fn negation_at_end_of_document_does_not_panic() {
+        let mut rules = compile("bad !idea /i priority=10 => nope # negation");
+        let document = Document::new_plain_english_curated("it was bad");
+
+        // Should simply find no match, not panic on an out-of-bounds span.
+        assert!(rules.lint(&document).is_empty());
+    }
+
+    #[test]
+    fn left_boundary_marker_rejects_when_a_word_precedes() {
+        assert_lint_count("bad", compile("^ bad /i priority=10 => nope # boundary"), 1);
+        assert_lint_count(
+            "a bad day",
+            compile("^ bad /i priority=10 => nope # boundary"),
+            0,
+        );
+    }
+
+    #[test]
+    fn right_boundary_marker_rejects_when_a_word_follows() {
+        assert_lint_count("bad", compile("bad $ /i priority=10 => nope # boundary"), 1);
+        assert_lint_count(
+            "a bad day",
+            compile("bad $ /i priority=10 => nope # boundary"),
+            0,
+        );
+    }
+
+    #[test]
+    fn uppercase_allowed_rejects_mixed_case() {
+        assert!(CaseMode::UppercaseAllowed.word_matches("BAD", "bad"));
+        assert!(CaseMode::UppercaseAllowed.word_matches("bad", "bad"));
+        assert!(!CaseMode::UppercaseAllowed.word_matches("Bad", "bad"));
+    }
+
+    #[test]
+    fn missing_replacement_is_an_error() {
+        assert_eq!(
+            RuleCompiler.compile("bad").err(),
+            Some(RuleCompileError::MissingReplacement)
+        );
+    }
+}
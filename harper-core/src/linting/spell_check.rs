@@ -0,0 +1,210 @@
+use super::{Lint, LintKind, Linter, Suggestion};
+use crate::{Document, TokenStringExt};
+
+/// Looks up every word token against a dictionary and, when it's absent,
+/// offers the closest-spelled dictionary words as corrections.
+pub struct SpellCheck<D: Dictionary> {
+    dictionary: D,
+    /// How many of the closest candidates to offer as suggestions.
+    max_suggestions: usize,
+}
+
+/// A word list a [`SpellCheck`] linter can consult.
+///
+/// Kept minimal and generic so the linter isn't tied to any one dictionary
+/// implementation (curated word lists, user dictionaries, etc. can all
+/// implement this).
+pub trait Dictionary {
+    /// Returns `true` if `word` is a recognized spelling.
+    fn contains(&self, word: &str) -> bool;
+    /// Iterates over every word in the dictionary.
+    fn words(&self) -> Box<dyn Iterator<Item = &str> + '_>;
+}
+
+impl<D: Dictionary> SpellCheck<D> {
+    pub fn new(dictionary: D) -> Self {
+        Self {
+            dictionary,
+            max_suggestions: 3,
+        }
+    }
+
+    pub fn with_max_suggestions(mut self, max_suggestions: usize) -> Self {
+        self.max_suggestions = max_suggestions;
+        self
+    }
+
+    /// Find the dictionary words closest to `word` by Damerau-Levenshtein
+    /// distance, bounded to roughly `word.len() / 3` edits (minimum 1).
+    fn suggest(&self, word: &str) -> Vec<String> {
+        let word_len = word.chars().count();
+        let max_distance = (word_len / 3).max(1);
+
+        let mut candidates: Vec<(usize, &str)> = self
+            .dictionary
+            .words()
+            .filter(|candidate| candidate.chars().count().abs_diff(word_len) <= max_distance)
+            .filter_map(|candidate| {
+                let distance = damerau_levenshtein(word, candidate, max_distance)?;
+                Some((distance, candidate))
+            })
+            .collect();
+
+        candidates.sort_by(|(dist_a, word_a), (dist_b, word_b)| {
+            dist_a.cmp(dist_b).then_with(|| word_a.cmp(word_b))
+        });
+
+        candidates
+            .into_iter()
+            .take(self.max_suggestions)
+            .map(|(_, word)| word.to_string())
+            .collect()
+    }
+}
+
+impl<D: Dictionary> Linter for SpellCheck<D> {
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        let mut lints = Vec::new();
+
+        for i in document.iter_word_indices() {
+            let token = document.get_token(i).unwrap();
+            if !token.kind.is_word() {
+                continue;
+            }
+
+            let word = document.get_span_content_str(&token.span);
+            if self.dictionary.contains(&word) {
+                continue;
+            }
+
+            let candidates = self.suggest(&word);
+            if candidates.is_empty() {
+                continue;
+            }
+
+            lints.push(Lint {
+                span: token.span,
+                lint_kind: LintKind::Spelling,
+                suggestions: candidates
+                    .into_iter()
+                    .map(|candidate| Suggestion::ReplaceWith(candidate.chars().collect()))
+                    .collect(),
+                message: format!("`{word}` does not appear in the dictionary."),
+                priority: 31,
+            });
+        }
+
+        lints
+    }
+
+    fn description(&self) -> &str {
+        "Flags words absent from the dictionary and suggests close-spelled replacements."
+    }
+}
+
+/// Computes the Damerau-Levenshtein edit distance between `a` and `b`,
+/// returning `None` early if it's provable to exceed `max_distance`.
+///
+/// Counts insertions, deletions, substitutions, and adjacent transpositions
+/// as a single edit each.
+fn damerau_levenshtein(a: &str, b: &str, max_distance: usize) -> Option<usize> {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    if a.len().abs_diff(b.len()) > max_distance {
+        return None;
+    }
+
+    let mut d = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for j in 0..=b.len() {
+        d[0][j] = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+
+    let distance = d[a.len()][b.len()];
+    (distance <= max_distance).then_some(distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Dictionary, SpellCheck, damerau_levenshtein};
+    use crate::Document;
+    use crate::linting::{Linter, Suggestion};
+
+    struct TestDictionary(Vec<&'static str>);
+
+    impl Dictionary for TestDictionary {
+        fn contains(&self, word: &str) -> bool {
+            self.0.iter().any(|w| w.eq_ignore_ascii_case(word))
+        }
+
+        fn words(&self) -> Box<dyn Iterator<Item = &str> + '_> {
+            Box::new(self.0.iter().copied())
+        }
+    }
+
+    #[test]
+    fn flags_misspelled_word_with_closest_suggestion() {
+        let mut linter = SpellCheck::new(TestDictionary(vec!["hello", "world"]));
+        let document = Document::new_plain_english_curated("hello wrold");
+
+        let lints = linter.lint(&document);
+        assert_eq!(lints.len(), 1);
+
+        let Suggestion::ReplaceWith(chars) = &lints[0].suggestions[0] else {
+            panic!("expected a ReplaceWith suggestion");
+        };
+        assert_eq!(chars.iter().collect::<String>(), "world");
+    }
+
+    #[test]
+    fn does_not_flag_known_words() {
+        let mut linter = SpellCheck::new(TestDictionary(vec!["hello", "world"]));
+        let document = Document::new_plain_english_curated("hello world");
+
+        assert!(linter.lint(&document).is_empty());
+    }
+
+    #[test]
+    fn identical_words_have_zero_distance() {
+        assert_eq!(damerau_levenshtein("hello", "hello", 5), Some(0));
+    }
+
+    #[test]
+    fn single_substitution() {
+        assert_eq!(damerau_levenshtein("hello", "hellp", 5), Some(1));
+    }
+
+    #[test]
+    fn single_transposition_counts_as_one_edit() {
+        assert_eq!(damerau_levenshtein("form", "from", 5), Some(1));
+    }
+
+    #[test]
+    fn insertion_and_deletion() {
+        assert_eq!(damerau_levenshtein("cat", "cats", 5), Some(1));
+        assert_eq!(damerau_levenshtein("cats", "cat", 5), Some(1));
+    }
+
+    #[test]
+    fn exceeding_max_distance_returns_none() {
+        assert_eq!(damerau_levenshtein("kitten", "sitting", 2), None);
+    }
+}
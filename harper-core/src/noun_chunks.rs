@@ -0,0 +1,198 @@
+use crate::patterns::{IndefiniteArticle, Pattern};
+use crate::{Span, Token, TokenStringExt};
+
+const DETERMINERS: &[&str] = &[
+    "the", "this", "that", "these", "those", "some", "any", "no", "every", "each", "either",
+    "neither", "all", "both",
+];
+
+const POSSESSIVE_PRONOUNS: &[&str] = &[
+    "my", "your", "his", "her", "its", "our", "their", "whose",
+];
+
+/// Extends [`TokenStringExt`] with noun-phrase chunking, so linters can ask
+/// "is this token the head of a noun phrase?" instead of maintaining ad-hoc
+/// whitelists of nouns that look like adjectives.
+pub trait NounChunkExt: TokenStringExt {
+    /// Iterate over the maximal noun-phrase spans in this token sequence.
+    ///
+    /// A chunk is found by scanning left-to-right for a noun token, then
+    /// extending leftward over any immediately preceding determiners
+    /// (including `a`/`an`), possessives, and a contiguous run of adjectives,
+    /// and extending rightward over any immediately following compound-noun
+    /// tokens (e.g. "grammar checker"). Postpositive modifiers (e.g. "the
+    /// thing itself", "a problem worth solving") aren't chunked -- only the
+    /// compound-noun case is handled on the right.
+    fn iter_noun_chunks(&self) -> NounChunkIter<'_> {
+        NounChunkIter {
+            tokens: self.get_tokens(),
+            source: self.get_source(),
+            cursor: 0,
+        }
+    }
+}
+
+impl<T: TokenStringExt + ?Sized> NounChunkExt for T {}
+
+/// Iterator over the noun-phrase [`Span`]s produced by [`NounChunkExt::iter_noun_chunks`].
+pub struct NounChunkIter<'a> {
+    tokens: &'a [Token],
+    source: &'a [char],
+    cursor: usize,
+}
+
+impl Iterator for NounChunkIter<'_> {
+    type Item = Span;
+
+    fn next(&mut self) -> Option<Span> {
+        while self.cursor < self.tokens.len() {
+            let head = self.cursor;
+            self.cursor += 1;
+
+            if !self.tokens[head].kind.is_noun() {
+                continue;
+            }
+
+            let tail = extend_over_compound_nouns(self.tokens, head);
+            let start = extend_left(self.tokens, self.source, head);
+
+            self.cursor = tail + 1;
+            return Some(Span::new(
+                self.tokens[start].span.start,
+                self.tokens[tail].span.end,
+            ));
+        }
+
+        None
+    }
+}
+
+/// Walk backward from the noun at `head`, consuming a contiguous run of
+/// adjectives, then an optional possessive, then an optional determiner.
+/// Returns the index of the leftmost token included in the chunk.
+fn extend_left(tokens: &[Token], source: &[char], head: usize) -> usize {
+    let mut i = head;
+
+    i = skip_back_over(tokens, i, |t| t.kind.is_adjective());
+
+    if let Some(prev) = previous_word_index(tokens, i) {
+        let word = token_text(tokens, source, prev);
+        if is_possessive(&word) || POSSESSIVE_PRONOUNS.iter().any(|p| p.eq_ignore_ascii_case(&word))
+        {
+            i = prev;
+        }
+    }
+
+    if let Some(prev) = previous_word_index(tokens, i) {
+        let word = token_text(tokens, source, prev);
+        if DETERMINERS.iter().any(|d| d.eq_ignore_ascii_case(&word))
+            || IndefiniteArticle::default()
+                .matches(&tokens[prev..], source)
+                .is_some()
+        {
+            i = prev;
+        }
+    }
+
+    i
+}
+
+/// Walk forward from the noun at `head` while subsequent word tokens are
+/// also nouns, for compound nouns like "grammar checker". Returns the index
+/// of the rightmost token included in the chunk.
+fn extend_over_compound_nouns(tokens: &[Token], head: usize) -> usize {
+    let mut tail = head;
+
+    while let Some(next) = next_word_index(tokens, tail) {
+        if !tokens[next].kind.is_noun() {
+            break;
+        }
+        tail = next;
+    }
+
+    tail
+}
+
+fn skip_back_over(tokens: &[Token], mut i: usize, pred: impl Fn(&Token) -> bool) -> usize {
+    while let Some(prev) = previous_word_index(tokens, i) {
+        if !pred(&tokens[prev]) {
+            break;
+        }
+        i = prev;
+    }
+    i
+}
+
+/// Index of the nearest preceding word token, skipping whitespace, or `None`
+/// if `i` is the first word in the sequence.
+fn previous_word_index(tokens: &[Token], i: usize) -> Option<usize> {
+    if i == 0 {
+        return None;
+    }
+    let prev = i - 1;
+    if tokens[prev].kind.is_whitespace() {
+        return previous_word_index(tokens, prev);
+    }
+    tokens[prev].kind.is_word().then_some(prev)
+}
+
+/// Index of the nearest following word token, skipping whitespace, or `None`
+/// if `i` is the last word in the sequence.
+fn next_word_index(tokens: &[Token], i: usize) -> Option<usize> {
+    let next = i + 1;
+    if next >= tokens.len() {
+        return None;
+    }
+    if tokens[next].kind.is_whitespace() {
+        return next_word_index(tokens, next);
+    }
+    tokens[next].kind.is_word().then_some(next)
+}
+
+fn token_text(tokens: &[Token], source: &[char], i: usize) -> String {
+    source[tokens[i].span.start..tokens[i].span.end]
+        .iter()
+        .collect()
+}
+
+fn is_possessive(word: &str) -> bool {
+    word.ends_with("'s") || word.ends_with("’s") || word.ends_with('\'') || word.ends_with('’')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NounChunkExt;
+    use crate::{Document, TokenStringExt};
+
+    fn chunk_texts(text: &str) -> Vec<String> {
+        let doc = Document::new_plain_english_curated(text);
+        doc.iter_noun_chunks()
+            .map(|span| doc.get_span_content_str(&span).to_string())
+            .collect()
+    }
+
+    #[test]
+    fn extends_over_determiner_and_adjectives() {
+        assert_eq!(chunk_texts("the big red barn"), vec!["the big red barn"]);
+    }
+
+    #[test]
+    fn extends_over_indefinite_article() {
+        assert_eq!(chunk_texts("a new idea"), vec!["a new idea"]);
+    }
+
+    #[test]
+    fn extends_over_possessive_pronoun() {
+        assert_eq!(chunk_texts("my old car"), vec!["my old car"]);
+    }
+
+    #[test]
+    fn extends_over_compound_nouns() {
+        assert_eq!(chunk_texts("grammar checker"), vec!["grammar checker"]);
+    }
+
+    #[test]
+    fn no_chunk_without_a_noun() {
+        assert!(chunk_texts("quickly ran").is_empty());
+    }
+}
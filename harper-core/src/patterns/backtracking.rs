@@ -0,0 +1,25 @@
+use crate::Token;
+
+use super::Pattern;
+
+/// A [`Pattern`] that can enumerate every length it would accept at a given
+/// position, not just the one it commits to by default.
+///
+/// Lets a containing sequence backtrack into a greedy sub-pattern instead of
+/// failing outright. Patterns with only one possible length can implement
+/// this with [`single_match_lengths`].
+pub trait BacktrackingPattern: Pattern {
+    /// Every length this pattern would accept here, ordered so the caller's
+    /// preferred choice (usually the greediest) comes first.
+    fn match_lengths(&self, tokens: &[Token], source: &[char]) -> Vec<usize>;
+}
+
+/// Implements [`BacktrackingPattern`] for a pattern whose `matches` already
+/// returns the only length it could ever accept.
+pub fn single_match_lengths<P: Pattern + ?Sized>(
+    pattern: &P,
+    tokens: &[Token],
+    source: &[char],
+) -> Vec<usize> {
+    pattern.matches(tokens, source).into_iter().collect()
+}
@@ -0,0 +1,87 @@
+use super::{BacktrackingPattern, Pattern};
+use crate::Token;
+
+/// A sequence of patterns matched one after another, with backtracking: if a
+/// later pattern can't match after an earlier one's first-choice length, the
+/// earlier pattern is asked for its next length instead of failing the whole
+/// sequence outright.
+///
+/// This is what lets `RepeatingPattern(word) + specific_word` succeed: the
+/// repetition's greedy match gives a token back when `specific_word` needs
+/// it, instead of the whole sequence failing.
+pub struct BacktrackingSequence {
+    patterns: Vec<Box<dyn BacktrackingPattern>>,
+}
+
+impl BacktrackingSequence {
+    pub fn new(patterns: Vec<Box<dyn BacktrackingPattern>>) -> Self {
+        Self { patterns }
+    }
+
+    fn matches_from(&self, stage: usize, tokens: &[Token], source: &[char]) -> Option<usize> {
+        self.match_lengths_from(stage, tokens, source).into_iter().next()
+    }
+
+    /// Every total length reachable from `stage` onward: the cross product
+    /// of each stage's own candidate lengths, pruned to the ones whose tail
+    /// actually goes on to match. Ordered by each stage's own preference
+    /// (earlier stages varying slowest), not by total length.
+    fn match_lengths_from(&self, stage: usize, tokens: &[Token], source: &[char]) -> Vec<usize> {
+        let Some(pattern) = self.patterns.get(stage) else {
+            return vec![0];
+        };
+
+        let mut lengths = Vec::new();
+        for len in pattern.match_lengths(tokens, source) {
+            // A sub-pattern (e.g. `Invert` matching an empty remainder)
+            // reporting a length past the slice it was given must not turn
+            // into an out-of-bounds slice index here.
+            if len > tokens.len() {
+                continue;
+            }
+
+            for rest in self.match_lengths_from(stage + 1, &tokens[len..], source) {
+                lengths.push(len + rest);
+            }
+        }
+
+        lengths
+    }
+}
+
+impl Pattern for BacktrackingSequence {
+    fn matches(&self, tokens: &[Token], source: &[char]) -> Option<usize> {
+        self.matches_from(0, tokens, source)
+    }
+}
+
+impl BacktrackingPattern for BacktrackingSequence {
+    /// Every total length the whole sequence could match here, in its
+    /// stages' preference order, so a [`BacktrackingSequence`] nested inside another one can
+    /// itself be backtracked into instead of only ever offering its first
+    /// match.
+    fn match_lengths(&self, tokens: &[Token], source: &[char]) -> Vec<usize> {
+        self.match_lengths_from(0, tokens, source)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BacktrackingSequence;
+    use crate::Document;
+    use crate::patterns::{AnyPattern, Pattern, RepeatingPattern, WordSet};
+
+    #[test]
+    fn greedy_repetition_gives_back_the_token_the_tail_needs() {
+        // A greedy `.+` swallows the whole document, including the final
+        // "day" token that the trailing literal match needs. Without
+        // backtracking this sequence would fail outright.
+        let doc = Document::new_plain_english_curated("have a nice day");
+        let pat = BacktrackingSequence::new(vec![
+            Box::new(RepeatingPattern::new(Box::new(AnyPattern), 1)),
+            Box::new(WordSet::new(&["day"])),
+        ]);
+
+        assert!(pat.matches(doc.get_tokens(), doc.get_source()).is_some());
+    }
+}
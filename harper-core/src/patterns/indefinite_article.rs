@@ -1,6 +1,6 @@
 use crate::Token;
 
-use super::{Pattern, WordSet};
+use super::{BacktrackingPattern, Pattern, WordSet, single_match_lengths};
 
 pub struct IndefiniteArticle {
     inner: WordSet,
@@ -19,3 +19,9 @@ impl Pattern for IndefiniteArticle {
         self.inner.matches(tokens, source)
     }
 }
+
+impl BacktrackingPattern for IndefiniteArticle {
+    fn match_lengths(&self, tokens: &[Token], source: &[char]) -> Vec<usize> {
+        single_match_lengths(self, tokens, source)
+    }
+}
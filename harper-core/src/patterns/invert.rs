@@ -1,6 +1,6 @@
 use crate::Token;
 
-use super::Pattern;
+use super::{BacktrackingPattern, Pattern, single_match_lengths};
 
 /// A struct that matches any pattern __except__ the one provided.
 pub struct Invert {
@@ -24,3 +24,9 @@ impl Pattern for Invert {
         }
     }
 }
+
+impl BacktrackingPattern for Invert {
+    fn match_lengths(&self, tokens: &[Token], source: &[char]) -> Vec<usize> {
+        single_match_lengths(self, tokens, source)
+    }
+}
@@ -0,0 +1,86 @@
+use super::{BacktrackingPattern, Pattern};
+use crate::Token;
+
+/// Like [`RepeatingPattern`](super::RepeatingPattern), but matches as few
+/// repetitions as possible by default: the lazy `.*?` counterpart to
+/// `RepeatingPattern`'s greedy `.*`.
+pub struct LazyRepeatingPattern {
+    inner: Box<dyn Pattern>,
+    required_repetitions: usize,
+}
+
+impl LazyRepeatingPattern {
+    pub fn new(pattern: Box<dyn Pattern>, required_repetitions: usize) -> Self {
+        Self {
+            inner: pattern,
+            required_repetitions,
+        }
+    }
+
+    /// All cumulative lengths reachable by repeating `inner`, shortest
+    /// achievable count first.
+    fn reachable_lengths(&self, tokens: &[Token], source: &[char]) -> Vec<usize> {
+        let mut lengths = Vec::new();
+        if self.required_repetitions == 0 {
+            lengths.push(0);
+        }
+
+        let mut tok_cursor = 0;
+        let mut repetition = 0;
+
+        loop {
+            match self.inner.matches(&tokens[tok_cursor..], source) {
+                Some(0) => {
+                    lengths.push(tok_cursor);
+                    break;
+                }
+                Some(len) => {
+                    tok_cursor += len;
+                    repetition += 1;
+                    if repetition >= self.required_repetitions {
+                        lengths.push(tok_cursor);
+                    }
+                }
+                None => break,
+            }
+        }
+
+        lengths.dedup();
+        lengths
+    }
+}
+
+impl Pattern for LazyRepeatingPattern {
+    fn matches(&self, tokens: &[Token], source: &[char]) -> Option<usize> {
+        self.reachable_lengths(tokens, source).first().copied()
+    }
+}
+
+impl BacktrackingPattern for LazyRepeatingPattern {
+    fn match_lengths(&self, tokens: &[Token], source: &[char]) -> Vec<usize> {
+        self.reachable_lengths(tokens, source)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LazyRepeatingPattern;
+    use crate::Document;
+    use crate::patterns::{AnyPattern, Pattern};
+
+    #[test]
+    fn matches_fewest_tokens_possible() {
+        let doc = Document::new_plain_english_curated("one two three");
+        let pat = LazyRepeatingPattern::new(Box::new(AnyPattern), 1);
+
+        assert_eq!(pat.matches(doc.get_tokens(), doc.get_source()), Some(1))
+    }
+
+    #[test]
+    fn does_not_match_short() {
+        let doc = Document::new_plain_english_curated("No match");
+        let pat = LazyRepeatingPattern::new(Box::new(AnyPattern), 4);
+
+        assert_eq!(pat.matches(doc.get_tokens(), doc.get_source()), None)
+    }
+}
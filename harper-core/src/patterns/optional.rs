@@ -0,0 +1,63 @@
+use crate::Token;
+
+use super::{BacktrackingPattern, Pattern};
+
+/// Matches zero or one occurrence of the wrapped pattern: the `?` operator
+/// from Regex.
+pub struct Optional {
+    inner: Box<dyn Pattern>,
+}
+
+impl Optional {
+    pub fn new(inner: impl Pattern + 'static) -> Self {
+        Self {
+            inner: Box::new(inner),
+        }
+    }
+}
+
+impl Pattern for Optional {
+    fn matches(&self, tokens: &[Token], source: &[char]) -> Option<usize> {
+        Some(self.inner.matches(tokens, source).unwrap_or(0))
+    }
+}
+
+impl BacktrackingPattern for Optional {
+    fn match_lengths(&self, tokens: &[Token], source: &[char]) -> Vec<usize> {
+        match self.inner.matches(tokens, source) {
+            Some(len) if len > 0 => vec![len, 0],
+            _ => vec![0],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Optional;
+    use crate::Document;
+    use crate::patterns::{BacktrackingPattern, Pattern, WordSet};
+
+    #[test]
+    fn matches_when_inner_matches() {
+        let doc = Document::new_plain_english_curated("a cat");
+        let pat = Optional::new(WordSet::new(&["a"]));
+
+        assert_eq!(pat.matches(doc.get_tokens(), doc.get_source()), Some(1));
+    }
+
+    #[test]
+    fn matches_zero_tokens_when_inner_does_not_match() {
+        let doc = Document::new_plain_english_curated("cat");
+        let pat = Optional::new(WordSet::new(&["a"]));
+
+        assert_eq!(pat.matches(doc.get_tokens(), doc.get_source()), Some(0));
+    }
+
+    #[test]
+    fn offers_the_present_length_before_the_absent_one() {
+        let doc = Document::new_plain_english_curated("a cat");
+        let pat = Optional::new(WordSet::new(&["a"]));
+
+        assert_eq!(pat.match_lengths(doc.get_tokens(), doc.get_source()), vec![1, 0]);
+    }
+}
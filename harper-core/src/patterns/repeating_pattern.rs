@@ -1,4 +1,4 @@
-use super::Pattern;
+use super::{BacktrackingPattern, Pattern};
 use crate::Token;
 
 /// A pattern that will match one or more repetitions of the same pattern.
@@ -45,6 +45,42 @@ impl Pattern for RepeatingPattern {
     }
 }
 
+impl BacktrackingPattern for RepeatingPattern {
+    /// Every cumulative length reachable by repeating `inner`, greediest
+    /// first, so a containing [`BacktrackingSequence`](super::BacktrackingSequence)
+    /// can give tokens back to a later pattern instead of failing outright.
+    fn match_lengths(&self, tokens: &[Token], source: &[char]) -> Vec<usize> {
+        let mut lengths = Vec::new();
+        if self.required_repetitions == 0 {
+            lengths.push(0);
+        }
+
+        let mut tok_cursor = 0;
+        let mut repetition = 0;
+
+        loop {
+            match self.inner.matches(&tokens[tok_cursor..], source) {
+                Some(0) => {
+                    lengths.push(tok_cursor);
+                    break;
+                }
+                Some(len) => {
+                    tok_cursor += len;
+                    repetition += 1;
+                    if repetition >= self.required_repetitions {
+                        lengths.push(tok_cursor);
+                    }
+                }
+                None => break,
+            }
+        }
+
+        lengths.reverse();
+        lengths.dedup();
+        lengths
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
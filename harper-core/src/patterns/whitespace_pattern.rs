@@ -1,4 +1,4 @@
-use super::Pattern;
+use super::{BacktrackingPattern, Pattern, single_match_lengths};
 
 pub struct WhitespacePattern;
 
@@ -12,3 +12,9 @@ impl Pattern for WhitespacePattern {
         if count == 0 { None } else { Some(count) }
     }
 }
+
+impl BacktrackingPattern for WhitespacePattern {
+    fn match_lengths(&self, tokens: &[crate::Token], source: &[char]) -> Vec<usize> {
+        single_match_lengths(self, tokens, source)
+    }
+}